@@ -31,6 +31,137 @@ pub fn check_path(path: &str) -> Result<PathExistsResult, String> {
     }
 }
 
+/// Metadata describing a single entry returned from `list_directory`
+#[derive(Serialize, Deserialize)]
+pub struct EntryMetadata {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub is_file: bool,
+    pub is_directory: bool,
+    pub is_symlink: bool,
+    pub created: Option<u64>,
+    pub modified: Option<u64>,
+    pub accessed: Option<u64>,
+    pub child_count: Option<u64>,
+    #[cfg(unix)]
+    pub permissions: String,
+    #[cfg(unix)]
+    pub rwx: String,
+}
+
+/// Convert a `SystemTime` to Unix epoch milliseconds, if representable
+fn to_epoch_millis(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis() as u64)
+}
+
+#[cfg(unix)]
+fn rwx_string(mode: u32) -> String {
+    let bits = [
+        (0o400, 'r'),
+        (0o200, 'w'),
+        (0o100, 'x'),
+        (0o040, 'r'),
+        (0o020, 'w'),
+        (0o010, 'x'),
+        (0o004, 'r'),
+        (0o002, 'w'),
+        (0o001, 'x'),
+    ];
+
+    bits.iter()
+        .map(|(mask, ch)| if mode & mask != 0 { *ch } else { '-' })
+        .collect()
+}
+
+#[cfg(all(test, unix))]
+mod rwx_tests {
+    use super::rwx_string;
+
+    #[test]
+    fn formats_full_permissions() {
+        assert_eq!(rwx_string(0o777), "rwxrwxrwx");
+    }
+
+    #[test]
+    fn formats_read_only() {
+        assert_eq!(rwx_string(0o444), "r--r--r--");
+    }
+
+    #[test]
+    fn formats_owner_only() {
+        assert_eq!(rwx_string(0o700), "rwx------");
+    }
+
+    #[test]
+    fn ignores_bits_outside_the_permission_mask() {
+        // setuid/setgid/sticky bits shouldn't leak into the rwx rendering
+        assert_eq!(rwx_string(0o4755), "rwxr-xr-x");
+    }
+}
+
+/// Build an `EntryMetadata` for a single directory entry, returning a string
+/// error for this entry alone so one unreadable entry doesn't abort the listing
+fn entry_metadata(entry: std::fs::DirEntry) -> Result<EntryMetadata, String> {
+    let path = entry.path();
+    let metadata = entry
+        .metadata()
+        .map_err(|e| format!("Failed to read metadata for {}: {}", path.display(), e))?;
+
+    let is_directory = metadata.is_dir();
+    let child_count = if is_directory {
+        std::fs::read_dir(&path).ok().map(|rd| rd.count() as u64)
+    } else {
+        None
+    };
+
+    #[cfg(unix)]
+    let (permissions, rwx) = {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode();
+        (format!("{:o}", mode & 0o777), rwx_string(mode))
+    };
+
+    Ok(EntryMetadata {
+        name: entry.file_name().to_string_lossy().to_string(),
+        path: path.to_string_lossy().to_string(),
+        size: metadata.len(),
+        is_file: metadata.is_file(),
+        is_directory,
+        is_symlink: metadata.file_type().is_symlink(),
+        created: to_epoch_millis(metadata.created()),
+        modified: to_epoch_millis(metadata.modified()),
+        accessed: to_epoch_millis(metadata.accessed()),
+        child_count,
+        #[cfg(unix)]
+        permissions,
+        #[cfg(unix)]
+        rwx,
+    })
+}
+
+/// List the contents of a directory with rich per-entry metadata
+#[tauri::command]
+pub fn list_directory(path: &str) -> Result<Vec<Result<EntryMetadata, String>>, String> {
+    let path = std::path::Path::new(path);
+
+    if !path.is_dir() {
+        return Err(format!("Not a directory: {}", path.display()));
+    }
+
+    let read_dir = std::fs::read_dir(path).map_err(|e| format!("Failed to read directory: {}", e))?;
+
+    Ok(read_dir
+        .map(|entry| match entry {
+            Ok(entry) => entry_metadata(entry),
+            Err(e) => Err(format!("Failed to read directory entry: {}", e)),
+        })
+        .collect())
+}
+
 /// Get the home directory path
 #[tauri::command]
 pub fn get_home_dir() -> Result<String, String> {
@@ -73,11 +204,868 @@ pub fn open_in_explorer(path: &str) -> Result<(), String> {
 
     #[cfg(target_os = "linux")]
     {
-        std::process::Command::new("xdg-open")
-            .arg(path_to_open)
+        let mut command = std::process::Command::new("xdg-open");
+        command.arg(path_to_open);
+        linux_env::sanitize(&mut command);
+        command.spawn().map_err(|e| format!("Failed to open file manager: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Sanitizes the environment handed to spawned child processes on Linux, so
+/// that a bundle running inside Flatpak/Snap/AppImage doesn't leak its
+/// sandbox-internal `PATH`/`LD_LIBRARY_PATH`/etc. into the file managers and
+/// default apps it launches
+#[cfg(target_os = "linux")]
+mod linux_env {
+    use std::sync::OnceLock;
+
+    /// Desktop sandbox (if any) this process is running inside
+    #[derive(PartialEq, Eq)]
+    enum SandboxKind {
+        Flatpak,
+        Snap,
+        AppImage,
+        None,
+    }
+
+    fn detect_sandbox() -> SandboxKind {
+        if std::env::var_os("FLATPAK_ID").is_some() {
+            SandboxKind::Flatpak
+        } else if std::env::var_os("SNAP").is_some() {
+            SandboxKind::Snap
+        } else if std::env::var_os("APPDIR").is_some() || std::env::var_os("APPIMAGE").is_some() {
+            SandboxKind::AppImage
+        } else {
+            SandboxKind::None
+        }
+    }
+
+    /// Path-list prefixes injected by each sandbox kind that should never be
+    /// forwarded to a process launched outside the sandbox
+    fn sandbox_prefixes(kind: &SandboxKind) -> &'static [&'static str] {
+        match kind {
+            SandboxKind::Flatpak => &["/app/", "/var/lib/flatpak/"],
+            SandboxKind::Snap => &["/snap/", "/var/lib/snapd/"],
+            SandboxKind::AppImage => &["/tmp/.mount_"],
+            SandboxKind::None => &[],
+        }
+    }
+
+    /// `PATH`/`XDG_DATA_DIRS` as read the first time a command is sanitized
+    /// inside a sandbox, cached from then on. The snapshot is not taken at
+    /// process start, so changes made to these variables before that first
+    /// call are part of it.
+    fn startup_snapshot() -> &'static (Option<String>, Option<String>) {
+        static SNAPSHOT: OnceLock<(Option<String>, Option<String>)> = OnceLock::new();
+        SNAPSHOT.get_or_init(|| (std::env::var("PATH").ok(), std::env::var("XDG_DATA_DIRS").ok()))
+    }
+
+    /// Strip sandbox-prefixed segments and empty segments from a colon-separated
+    /// path list, de-duplicating while preferring later (less-privileged) entries
+    fn clean_path_list(value: &str, prefixes: &[&str]) -> String {
+        let mut seen = std::collections::HashSet::new();
+        let mut kept = Vec::new();
+
+        for segment in value.split(':').rev() {
+            if segment.is_empty() {
+                continue;
+            }
+            if prefixes.iter().any(|prefix| segment.starts_with(prefix)) {
+                continue;
+            }
+            if seen.insert(segment) {
+                kept.push(segment);
+            }
+        }
+
+        kept.reverse();
+        kept.join(":")
+    }
+
+    /// Apply a sanitized environment to `command`, setting `PATH`/`XDG_DATA_DIRS`
+    /// from the cached `startup_snapshot` and stripping sandbox-injected
+    /// directories from the other path-list variables. No-op outside a
+    /// detected sandbox.
+    pub fn sanitize(command: &mut std::process::Command) {
+        let kind = detect_sandbox();
+        if kind == SandboxKind::None {
+            return;
+        }
+
+        let prefixes = sandbox_prefixes(&kind);
+        let (startup_path, startup_xdg_data_dirs) = startup_snapshot();
+
+        if let Some(path) = startup_path {
+            let cleaned = clean_path_list(path, prefixes);
+            if !cleaned.is_empty() {
+                command.env("PATH", cleaned);
+            }
+        }
+
+        if let Some(xdg_data_dirs) = startup_xdg_data_dirs {
+            let cleaned = clean_path_list(xdg_data_dirs, prefixes);
+            if !cleaned.is_empty() {
+                command.env("XDG_DATA_DIRS", cleaned);
+            }
+        }
+
+        for var in ["LD_LIBRARY_PATH", "GST_PLUGIN_PATH", "XDG_CONFIG_DIRS"] {
+            if let Ok(value) = std::env::var(var) {
+                let cleaned = clean_path_list(&value, prefixes);
+                if cleaned.is_empty() {
+                    command.env_remove(var);
+                } else {
+                    command.env(var, cleaned);
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::clean_path_list;
+
+        #[test]
+        fn strips_sandbox_prefixed_segments() {
+            let value = "/app/bin:/usr/bin:/usr/local/bin";
+            assert_eq!(clean_path_list(value, &["/app/"]), "/usr/bin:/usr/local/bin");
+        }
+
+        #[test]
+        fn drops_empty_segments() {
+            assert_eq!(clean_path_list("/usr/bin::/usr/local/bin:", &[]), "/usr/bin:/usr/local/bin");
+        }
+
+        #[test]
+        fn dedupes_preferring_the_later_entry() {
+            // "/usr/bin" appears twice; the later occurrence should win and
+            // the list should otherwise keep its original relative order
+            let value = "/usr/bin:/opt/bin:/usr/bin";
+            assert_eq!(clean_path_list(value, &[]), "/opt/bin:/usr/bin");
+        }
+
+        #[test]
+        fn no_prefixes_passes_list_through_unchanged_modulo_dedup() {
+            assert_eq!(clean_path_list("/a:/b:/c", &[]), "/a:/b:/c");
+        }
+    }
+}
+
+/// Reveal a file or folder in the system file explorer, selecting the item
+/// itself rather than just opening its parent directory
+#[tauri::command]
+pub fn reveal_in_explorer(path: &str) -> Result<(), String> {
+    let path = std::path::Path::new(path);
+
+    if !path.exists() {
+        return Err(format!("Path does not exist: {}", path.display()));
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        // `Command::arg` always backslash-escapes embedded `"` characters
+        // when building the child's command line, which would turn
+        // `/select,"<path>"` into the literal (and rejected) `/select,\"<path>\"`.
+        // `raw_arg` bypasses that quoting so explorer.exe sees the syntax it expects.
+        use std::os::windows::process::CommandExt;
+
+        std::process::Command::new("explorer")
+            .raw_arg(format!("/select,\"{}\"", path.display()))
+            .spawn()
+            .map_err(|e| format!("Failed to open explorer: {}", e))?;
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("open")
+            .arg("-R")
+            .arg(path)
             .spawn()
-            .map_err(|e| format!("Failed to open file manager: {}", e))?;
+            .map_err(|e| format!("Failed to open finder: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        reveal_in_explorer_linux(path)?;
+    }
+
+    Ok(())
+}
+
+/// Percent-encode a path's non-ASCII, reserved, and whitespace bytes so it can
+/// be embedded in a `file://` URI per the freedesktop spec that
+/// `org.freedesktop.FileManager1` relies on
+#[cfg(target_os = "linux")]
+fn percent_encode_path(path: &std::path::Path) -> String {
+    const UNRESERVED: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_.~/";
+
+    let mut encoded = String::new();
+    for byte in path.to_string_lossy().as_bytes() {
+        if UNRESERVED.contains(byte) {
+            encoded.push(*byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Ask the running file manager to select `path` via the freedesktop
+/// `org.freedesktop.FileManager1` D-Bus interface, falling back to opening
+/// the parent directory when no file manager is listening on the bus
+#[cfg(target_os = "linux")]
+fn reveal_in_explorer_linux(path: &std::path::Path) -> Result<(), String> {
+    use zbus::blocking::Connection;
+    use zbus::dbus_proxy;
+
+    #[dbus_proxy(
+        interface = "org.freedesktop.FileManager1",
+        default_path = "/org/freedesktop/FileManager1"
+    )]
+    trait FileManager1 {
+        fn show_items(&self, uris: &[&str], startup_id: &str) -> zbus::Result<()>;
+    }
+
+    let uri = format!("file://{}", percent_encode_path(path));
+
+    let fallback = |e: String| -> Result<(), String> {
+        let parent = path
+            .parent()
+            .ok_or_else(|| "Could not get parent directory".to_string())?;
+        let mut command = std::process::Command::new("xdg-open");
+        command.arg(parent);
+        linux_env::sanitize(&mut command);
+        command
+            .spawn()
+            .map_err(|spawn_err| format!("{e}; fallback also failed: {spawn_err}"))?;
+        Ok(())
+    };
+
+    let connection = match Connection::session() {
+        Ok(c) => c,
+        Err(e) => return fallback(format!("Could not connect to session bus: {}", e)),
+    };
+
+    let proxy = match FileManager1ProxyBlocking::new(&connection) {
+        Ok(p) => p,
+        Err(e) => return fallback(format!("Could not reach FileManager1 service: {}", e)),
+    };
+
+    match proxy.show_items(&[&uri], "") {
+        Ok(()) => Ok(()),
+        Err(e) => fallback(format!("ShowItems call failed: {}", e)),
+    }
+}
+
+/// Error launching a path or URL in its default application, kept distinct
+/// internally so callers can tell "nothing to open" apart from "the opener
+/// itself failed", even though both are flattened to a string at the IPC boundary
+enum OpenError {
+    /// The path/URL does not exist or is malformed
+    NotFound(String),
+    /// The launcher could not be spawned or waited on, or it exited
+    /// unsuccessfully. `status` is `None` when there is no exit code (spawn
+    /// failure, wait failure, or termination by a signal); on Windows it holds
+    /// the `ShellExecuteW` return code.
+    LaunchFailed { status: Option<i32>, stderr: String },
+}
+
+impl std::fmt::Display for OpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenError::NotFound(msg) => write!(f, "{}", msg),
+            OpenError::LaunchFailed { status, stderr } => {
+                let stderr = stderr.trim();
+                match status {
+                    Some(code) if stderr.is_empty() => {
+                        write!(f, "Launcher exited with status {}", code)
+                    }
+                    Some(code) => write!(f, "Launcher exited with status {}: {}", code, stderr),
+                    None if stderr.is_empty() => write!(f, "Launcher failed without an exit status"),
+                    None => write!(f, "Launcher failed: {}", stderr),
+                }
+            }
+        }
+    }
+}
+
+/// How long to wait for a launched process to fail fast (e.g. a missing
+/// binary or a malformed argument) before treating it as successfully
+/// launched. Most launchers either double-fork and return almost immediately
+/// or exit non-zero right away; this avoids blocking for the lifetime of a
+/// long-running GUI process such as a `$BROWSER` override that doesn't detach.
+const LAUNCH_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// Spawn `command` and map its outcome to an `OpenError::LaunchFailed` on a
+/// non-zero exit, capturing the exit status and stderr. Does not block on the
+/// full lifetime of the child: it only waits out a short grace period, then
+/// reports success if the process is still running.
+fn run_launcher(mut command: std::process::Command) -> Result<(), OpenError> {
+    #[cfg(target_os = "linux")]
+    linux_env::sanitize(&mut command);
+
+    command.stdout(std::process::Stdio::null());
+    command.stderr(std::process::Stdio::piped());
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| OpenError::LaunchFailed { status: None, stderr: e.to_string() })?;
+
+    std::thread::sleep(LAUNCH_GRACE_PERIOD);
+
+    match child.try_wait() {
+        Ok(Some(status)) if !status.success() => {
+            let mut stderr = String::new();
+            if let Some(mut stderr_pipe) = child.stderr.take() {
+                use std::io::Read;
+                let _ = stderr_pipe.read_to_string(&mut stderr);
+            }
+            Err(OpenError::LaunchFailed { status: status.code(), stderr })
+        }
+        Ok(_) => Ok(()),
+        Err(e) => Err(OpenError::LaunchFailed { status: None, stderr: e.to_string() }),
+    }
+}
+
+/// Open `target` (a file path or URL) in its default application
+fn open_with_default_app(target: &str) -> Result<(), OpenError> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::HSTRING;
+        use windows::Win32::UI::Shell::ShellExecuteW;
+        use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+        let operation = HSTRING::from("open");
+        let file = HSTRING::from(target);
+
+        let result = unsafe {
+            ShellExecuteW(
+                None,
+                &operation,
+                &file,
+                None,
+                None,
+                SW_SHOWNORMAL.0 as i32,
+            )
+        };
+
+        // ShellExecuteW returns a value > 32 on success
+        if (result.0 as isize) <= 32 {
+            return Err(OpenError::LaunchFailed {
+                status: Some(result.0 as i32),
+                stderr: String::new(),
+            });
+        }
+
+        return Ok(());
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        return run_launcher({
+            let mut command = std::process::Command::new("open");
+            command.arg(target);
+            command
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        return run_launcher({
+            let mut command = std::process::Command::new("xdg-open");
+            command.arg(target);
+            command
+        });
+    }
+}
+
+/// Open an arbitrary file or folder in its default application
+#[tauri::command]
+pub fn open_path(path: &str) -> Result<(), String> {
+    let path_buf = std::path::Path::new(path);
+
+    if !path_buf.exists() {
+        return Err(OpenError::NotFound(format!("Path does not exist: {}", path_buf.display())).to_string());
+    }
+
+    open_with_default_app(path).map_err(|e| e.to_string())
+}
+
+/// Open a URL in the default browser, honoring a `$BROWSER` override so users
+/// can force a specific browser instead of whatever the OS considers default
+#[tauri::command]
+pub fn open_url(url: &str) -> Result<(), String> {
+    if url.trim().is_empty() || url::Url::parse(url).is_err() {
+        return Err(OpenError::NotFound(format!("Malformed URL: {}", url)).to_string());
+    }
+
+    if let Ok(browser) = std::env::var("BROWSER") {
+        if !browser.trim().is_empty() {
+            return run_launcher({
+                let mut command = std::process::Command::new(browser);
+                command.arg(url);
+                command
+            })
+            .map_err(|e| e.to_string());
+        }
+    }
+
+    open_with_default_app(url).map_err(|e| e.to_string())
+}
+
+/// An application that can open a given file, as surfaced by `list_openers`.
+/// `id` is the `.desktop` file's stem (e.g. `org.gnome.TextEditor`) and is
+/// what must be passed back into `open_with`'s `app` parameter on Linux.
+#[derive(Serialize, Deserialize)]
+pub struct AppInfo {
+    pub id: String,
+    pub name: String,
+    pub exec: String,
+    pub icon: Option<String>,
+}
+
+/// Open `path` with a specific application. When `app` is `None`, Windows
+/// shows its "Open With" picker (`openwith.exe`) and macOS reveals the item in
+/// Finder; Linux shows no picker and returns an error.
+#[tauri::command]
+pub fn open_with(path: &str, app: Option<&str>) -> Result<(), String> {
+    let path_buf = std::path::Path::new(path);
+
+    if !path_buf.exists() {
+        return Err(format!("Path does not exist: {}", path_buf.display()));
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let mut command = match app {
+            Some(app) => {
+                let mut command = std::process::Command::new("cmd");
+                command.args(["/C", "start", "", app]).arg(path);
+                command
+            }
+            None => {
+                let mut command = std::process::Command::new("openwith.exe");
+                command.arg(path);
+                command
+            }
+        };
+        command.spawn().map_err(|e| format!("Failed to launch: {}", e))?;
+        return Ok(());
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        match app {
+            Some(app) => {
+                let mut command = std::process::Command::new("open");
+                command.args(["-a", app]).arg(path);
+                command.spawn().map_err(|e| format!("Failed to launch {}: {}", app, e))?;
+            }
+            None => {
+                // macOS has no CLI "Open With" picker; reveal the item in
+                // Finder so the user can pick an app from its context menu
+                let mut command = std::process::Command::new("open");
+                command.arg("-R").arg(path);
+                command.spawn().map_err(|e| format!("Failed to open finder: {}", e))?;
+            }
+        }
+        return Ok(());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        match app {
+            Some(app) => {
+                let mut command = std::process::Command::new("gtk-launch");
+                command.arg(app).arg(path);
+                return run_launcher(command).map_err(|e| e.to_string());
+            }
+            None => {
+                return Err(
+                    "Linux has no native \"Open With\" picker; call list_openers and pass a specific app"
+                        .to_string(),
+                );
+            }
+        }
+    }
+}
+
+/// List the candidate applications registered to open `path`'s file type
+#[tauri::command]
+pub fn list_openers(path: &str) -> Result<Vec<AppInfo>, String> {
+    #[cfg(target_os = "linux")]
+    {
+        linux_openers::list_openers(path)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = path;
+        Err("list_openers is only implemented on Linux".to_string())
+    }
+}
+
+/// Resolve candidate `.desktop` applications for a file's MIME type on Linux
+#[cfg(target_os = "linux")]
+mod linux_openers {
+    use super::AppInfo;
+
+    /// Directories that may contain `.desktop` entries, per the freedesktop
+    /// application directory spec
+    fn application_dirs() -> Vec<std::path::PathBuf> {
+        let mut dirs = Vec::new();
+
+        let data_home = std::env::var("XDG_DATA_HOME")
+            .map(std::path::PathBuf::from)
+            .or_else(|_| dirs::home_dir().map(|h| h.join(".local/share")).ok_or(()))
+            .unwrap_or_else(|_| std::path::PathBuf::from(".local/share"));
+        dirs.push(data_home.join("applications"));
+
+        let data_dirs = std::env::var("XDG_DATA_DIRS")
+            .unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+        for dir in data_dirs.split(':').filter(|d| !d.is_empty()) {
+            dirs.push(std::path::PathBuf::from(dir).join("applications"));
+        }
+
+        dirs
+    }
+
+    fn query_mime_type(path: &str) -> Result<String, String> {
+        let mut command = std::process::Command::new("xdg-mime");
+        command.args(["query", "filetype", path]);
+        super::linux_env::sanitize(&mut command);
+
+        let output = command
+            .output()
+            .map_err(|e| format!("Failed to query MIME type: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "xdg-mime exited with status {}",
+                output.status.code().map(|c| c.to_string()).unwrap_or_else(|| "unknown".to_string())
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Parse the `[Desktop Entry]` section of a `.desktop` file, returning
+    /// `Some(AppInfo)` when it declares `mime_type` among its `MimeType=` list.
+    /// `id` is the file's stem, e.g. `firefox` for `firefox.desktop`.
+    fn parse_desktop_entry(contents: &str, mime_type: &str, id: &str) -> Option<AppInfo> {
+        let mut in_desktop_entry = false;
+        let mut name = None;
+        let mut exec = None;
+        let mut icon = None;
+        let mut matches_mime = false;
+
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.starts_with('[') {
+                in_desktop_entry = line == "[Desktop Entry]";
+                continue;
+            }
+            if !in_desktop_entry {
+                continue;
+            }
+
+            if let Some(value) = line.strip_prefix("Name=") {
+                name = Some(value.to_string());
+            } else if let Some(value) = line.strip_prefix("Exec=") {
+                exec = Some(value.to_string());
+            } else if let Some(value) = line.strip_prefix("Icon=") {
+                icon = Some(value.to_string());
+            } else if let Some(value) = line.strip_prefix("MimeType=") {
+                matches_mime = value.split(';').any(|m| m == mime_type);
+            }
+        }
+
+        if !matches_mime {
+            return None;
+        }
+
+        Some(AppInfo { id: id.to_string(), name: name?, exec: exec?, icon })
+    }
+
+    pub fn list_openers(path: &str) -> Result<Vec<AppInfo>, String> {
+        let mime_type = query_mime_type(path)?;
+        let mut openers = Vec::new();
+
+        for dir in application_dirs() {
+            let Ok(read_dir) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in read_dir.flatten() {
+                let entry_path = entry.path();
+                if entry_path.extension().and_then(|e| e.to_str()) != Some("desktop") {
+                    continue;
+                }
+
+                let Ok(contents) = std::fs::read_to_string(&entry_path) else {
+                    continue;
+                };
+
+                let Some(id) = entry_path.file_stem().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+
+                if let Some(app) = parse_desktop_entry(&contents, &mime_type, id) {
+                    openers.push(app);
+                }
+            }
+        }
+
+        Ok(openers)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse_desktop_entry;
+
+        const FIREFOX_DESKTOP: &str = "[Desktop Entry]\n\
+            Name=Firefox\n\
+            Exec=firefox %u\n\
+            Icon=firefox\n\
+            MimeType=text/html;x-scheme-handler/http;x-scheme-handler/https;\n";
+
+        #[test]
+        fn matches_a_declared_mime_type() {
+            let app = parse_desktop_entry(FIREFOX_DESKTOP, "text/html", "firefox").unwrap();
+            assert_eq!(app.id, "firefox");
+            assert_eq!(app.name, "Firefox");
+            assert_eq!(app.exec, "firefox %u");
+            assert_eq!(app.icon.as_deref(), Some("firefox"));
+        }
+
+        #[test]
+        fn rejects_an_undeclared_mime_type() {
+            assert!(parse_desktop_entry(FIREFOX_DESKTOP, "application/pdf", "firefox").is_none());
+        }
+
+        #[test]
+        fn ignores_keys_outside_the_desktop_entry_section() {
+            let contents = "[Desktop Entry]\n\
+                Name=Editor\n\
+                Exec=editor %f\n\
+                MimeType=text/plain;\n\
+                [Desktop Action NewWindow]\n\
+                Name=New Window\n\
+                Exec=editor --new-window\n";
+
+            let app = parse_desktop_entry(contents, "text/plain", "editor").unwrap();
+            assert_eq!(app.exec, "editor %f");
+        }
+
+        #[test]
+        fn missing_required_fields_yield_none() {
+            let contents = "[Desktop Entry]\nMimeType=text/plain;\n";
+            assert!(parse_desktop_entry(contents, "text/plain", "incomplete").is_none());
+        }
     }
+}
 
+/// Standard per-app directories resolved via the `dirs` crate
+#[derive(Serialize, Deserialize)]
+pub struct AppDirs {
+    pub config: Option<String>,
+    pub data: Option<String>,
+    pub cache: Option<String>,
+    pub document: Option<String>,
+}
+
+/// Get the platform's standard config, data, cache, and document directories
+#[tauri::command]
+pub fn get_app_dirs() -> Result<AppDirs, String> {
+    Ok(AppDirs {
+        config: dirs::config_dir().map(|p| p.to_string_lossy().to_string()),
+        data: dirs::data_dir().map(|p| p.to_string_lossy().to_string()),
+        cache: dirs::cache_dir().map(|p| p.to_string_lossy().to_string()),
+        document: dirs::document_dir().map(|p| p.to_string_lossy().to_string()),
+    })
+}
+
+/// Reject app/developer names that could be used as a path-traversal or
+/// separator-injection component rather than a single directory segment.
+/// `.` is rejected outright (not just exact `"."`/`".."`) because it's the
+/// separator `app_root_path` uses to join `developer` and `app_name` — if
+/// either component could contain one, two distinct apps could be made to
+/// collide on the same storage root (e.g. `developer="acme.widgets"` +
+/// `app_name="suite"` vs. `developer="acme"` + `app_name="widgets.suite"`).
+fn validate_namespace_component(value: &str, field: &str) -> Result<(), String> {
+    if value.is_empty() || value.contains(['/', '\\', '.']) {
+        return Err(format!("Invalid {}: {}", field, value));
+    }
     Ok(())
 }
+
+/// Compute the namespaced app root path (without creating it), validating
+/// that `app_name`/`developer` are single path segments so the result can
+/// never land outside `data_dir`
+fn app_root_path(app_name: &str, developer: &str) -> Result<std::path::PathBuf, String> {
+    validate_namespace_component(app_name, "app_name")?;
+    validate_namespace_component(developer, "developer")?;
+
+    let data_dir = dirs::data_dir().ok_or_else(|| "Could not determine data directory".to_string())?;
+    Ok(data_dir.join(format!("{}.{}", developer, app_name)))
+}
+
+/// Construct (and create, if missing) a stable per-application root folder
+/// namespaced by app and developer, e.g. `<data_dir>/<developer>.<app_name>/`
+#[tauri::command]
+pub fn ensure_app_root(app_name: &str, developer: &str) -> Result<String, String> {
+    let root = app_root_path(app_name, developer)?;
+
+    std::fs::create_dir_all(&root)
+        .map_err(|e| format!("Failed to create app root {}: {}", root.display(), e))?;
+
+    Ok(root.to_string_lossy().to_string())
+}
+
+/// Resolve `relative_path` against `root`, rejecting any path that escapes it.
+/// The target file need not exist yet, so this checks the requested path
+/// lexically against the canonicalized root rather than canonicalizing the
+/// (possibly not-yet-created) target itself.
+fn resolve_within_root(root: &std::path::Path, relative_path: &str) -> Result<std::path::PathBuf, String> {
+    let relative = std::path::Path::new(relative_path);
+
+    if relative.is_absolute()
+        || relative
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return Err(format!("Path escapes app root: {}", relative_path));
+    }
+
+    let canonical_root = root
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve app root {}: {}", root.display(), e))?;
+
+    let resolved = canonical_root.join(relative);
+
+    if !resolved.starts_with(&canonical_root) {
+        return Err(format!("Path escapes app root: {}", relative_path));
+    }
+
+    Ok(resolved)
+}
+
+/// Read a UTF-8 file relative to the app root namespaced by `app_name`/`developer`.
+/// The root is re-derived the same way `ensure_app_root` builds it rather than
+/// trusting a caller-supplied path, so this can only ever read inside the
+/// app's own storage directory.
+#[tauri::command]
+pub fn read_app_file(app_name: &str, developer: &str, relative_path: &str) -> Result<String, String> {
+    let root = app_root_path(app_name, developer)?;
+    let path = resolve_within_root(&root, relative_path)?;
+    std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// Write a UTF-8 file relative to the app root namespaced by `app_name`/`developer`,
+/// creating the root and any missing parent directories. As with
+/// `read_app_file`, the root is re-derived server-side rather than trusting a
+/// caller-supplied path.
+#[tauri::command]
+pub fn write_app_file(
+    app_name: &str,
+    developer: &str,
+    relative_path: &str,
+    contents: &str,
+) -> Result<(), String> {
+    let root = app_root_path(app_name, developer)?;
+    std::fs::create_dir_all(&root).map_err(|e| format!("Failed to create app root {}: {}", root.display(), e))?;
+
+    let path = resolve_within_root(&root, relative_path)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {}", parent.display(), e))?;
+    }
+
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod app_root_tests {
+    use super::{resolve_within_root, validate_namespace_component};
+
+    #[test]
+    fn validate_namespace_component_rejects_traversal_and_separators() {
+        assert!(validate_namespace_component("..", "developer").is_err());
+        assert!(validate_namespace_component(".", "developer").is_err());
+        assert!(validate_namespace_component("", "developer").is_err());
+        assert!(validate_namespace_component("../../etc", "developer").is_err());
+        assert!(validate_namespace_component("a/b", "developer").is_err());
+        assert!(validate_namespace_component("a\\b", "developer").is_err());
+    }
+
+    #[test]
+    fn validate_namespace_component_rejects_embedded_dots() {
+        // A `.` would let two distinct (developer, app_name) pairs collide
+        // on the same "<developer>.<app_name>" storage root
+        assert!(validate_namespace_component("acme.widgets", "developer").is_err());
+        assert!(validate_namespace_component("widgets.suite", "app_name").is_err());
+    }
+
+    #[test]
+    fn app_root_path_does_not_collide_across_component_boundaries() {
+        // Without the dot guard, these two (developer, app_name) pairs would
+        // both resolve to "acme.widgets.suite"
+        assert!(super::app_root_path("suite", "acme.widgets").is_err());
+        assert!(super::app_root_path("widgets.suite", "acme").is_err());
+    }
+
+    #[test]
+    fn validate_namespace_component_accepts_a_plain_segment() {
+        assert!(validate_namespace_component("acme", "developer").is_ok());
+        assert!(validate_namespace_component("My-App_2", "app_name").is_ok());
+    }
+
+    /// A throwaway directory under the OS temp dir, removed on drop
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(label: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "projelli-fs-test-{}-{}-{:?}",
+                label,
+                std::process::id(),
+                std::thread::current().id()
+            ));
+            std::fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn resolve_within_root_accepts_a_nested_relative_path() {
+        let root = TempDir::new("accepts-nested");
+        let resolved = resolve_within_root(&root.0, "sub/settings.json").unwrap();
+        assert_eq!(resolved, root.0.canonicalize().unwrap().join("sub/settings.json"));
+    }
+
+    #[test]
+    fn resolve_within_root_rejects_parent_dir_traversal() {
+        let root = TempDir::new("rejects-traversal");
+        assert!(resolve_within_root(&root.0, "../escaped.txt").is_err());
+        assert!(resolve_within_root(&root.0, "sub/../../escaped.txt").is_err());
+    }
+
+    #[test]
+    fn resolve_within_root_rejects_an_absolute_path() {
+        let root = TempDir::new("rejects-absolute");
+        #[cfg(unix)]
+        assert!(resolve_within_root(&root.0, "/etc/passwd").is_err());
+        #[cfg(windows)]
+        assert!(resolve_within_root(&root.0, r"C:\Windows\System32\evil.dll").is_err());
+    }
+}